@@ -8,7 +8,13 @@ fn main() {
 
         let result = request.await;
 
-        println!("Done! {:?}", result);
+        match result {
+            Ok(response) => {
+                let status_code = response.status_code;
+                println!("Done! {} {:?}", status_code, response.into_string())
+            }
+            Err(error) => println!("Request failed: {}", error),
+        }
     };
 
     block_on(future);
@@ -6,6 +6,8 @@ use std::future::Future;
 use std::task::{Context, Poll, Waker};
 use std::pin::Pin;
 use std::sync::{Arc, Mutex};
+#[cfg(not(target_arch = "wasm32"))]
+use std::io::Read;
 
 #[derive(Debug, Clone, PartialEq, Copy)]
 pub enum Method {
@@ -18,6 +20,7 @@ pub enum Method {
 #[derive(Debug)]
 pub enum HttpError {
     IOError,
+    Timeout,
     #[cfg(not(target_arch = "wasm32"))]
     UreqError(ureq::Error),
 }
@@ -26,6 +29,7 @@ impl std::fmt::Display for HttpError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             HttpError::IOError => write!(f, "IOError"),
+            HttpError::Timeout => write!(f, "Request timed out"),
             #[cfg(not(target_arch = "wasm32"))]
             HttpError::UreqError(error) => write!(f, "Ureq error: {}", error),
         }
@@ -46,22 +50,66 @@ impl From<ureq::Error> for HttpError {
 
 #[cfg(target_arch = "wasm32")]
 extern "C" {
-    fn http_make_request(scheme: i32, url: JsObject, body: JsObject, headers: JsObject) -> i32;
+    // `timeout_ms` is negative when the request has no timeout; otherwise the
+    // JS side drives an `AbortController` off it.
+    fn http_make_request(
+        scheme: i32,
+        url: JsObject,
+        body: JsObject,
+        headers: JsObject,
+        timeout_ms: i32,
+    ) -> i32;
     fn http_try_recv(cid: i32) -> JsObject;
+    // Returns the next body chunk for a streaming request, a nil object while
+    // none has arrived yet, or an object with its `done` field set once the
+    // underlying `ReadableStream` is exhausted.
+    fn http_try_recv_chunk(cid: i32) -> JsObject;
+}
+
+/// The result of a completed request: status line and headers, plus the body.
+///
+/// Unlike the body, `status_code` and `headers` are always known up front, so
+/// they are plain fields rather than something you have to consume the
+/// response to get at.
+pub struct Response {
+    pub status_code: u16,
+    pub headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl Response {
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.body
+    }
+
+    pub fn into_string(self) -> Result<String, HttpError> {
+        String::from_utf8(self.body).map_err(|_| HttpError::IOError)
+    }
 }
 
+// Dropping a pending `Request`/`ResponseStream` only flags it as cancelled;
+// the worker thread doesn't notice until its blocking `ureq` call returns.
+// Without a bound on that call, a caller that drops a request against a
+// hung server still leaks the thread forever, so every request gets this
+// deadline even if it never calls `.timeout(...)`.
+#[cfg(not(target_arch = "wasm32"))]
+const DEFAULT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
 #[cfg(not(target_arch = "wasm32"))]
 pub struct Request {
-    shared_state: Arc<Mutex<SharedState>>
+    shared_state: Arc<Mutex<SharedState>>,
+    cancelled: Arc<std::sync::atomic::AtomicBool>,
 }
 
 struct SharedState {
-    rx: std::sync::mpsc::Receiver<Result<String, HttpError>>,
+    rx: std::sync::mpsc::Receiver<Result<Response, HttpError>>,
     waker: Option<Waker>,
+    deadline: Option<std::time::Instant>,
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl Future for Request {
-    type Output = Result<String, HttpError>;
+    type Output = Result<Response, HttpError>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let mut shared_state = self.shared_state.lock().unwrap();
@@ -69,16 +117,109 @@ impl Future for Request {
             return Poll::Ready(result)
         }
 
+        if let Some(deadline) = shared_state.deadline {
+            if std::time::Instant::now() >= deadline {
+                return Poll::Ready(Err(HttpError::Timeout));
+            }
+        }
+
         shared_state.waker = Some(cx.waker().clone());
         Poll::Pending
     }
 }
 
+// Dropping a pending `Request` abandons the `Future`, but the worker thread
+// doing the blocking `ureq` call would otherwise run to completion regardless.
+// Flagging it as cancelled lets the thread skip delivering a result nobody is
+// waiting for, instead of panicking on a send to a receiver that's gone.
 #[cfg(not(target_arch = "wasm32"))]
-impl Request {
-    // pub fn try_recv(&mut self) -> Option<Result<String, HttpError>> {
-    //     self.rx.try_recv().ok()
-    // }
+impl Drop for Request {
+    fn drop(&mut self) {
+        self.cancelled.store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+/// A handle to a response body that hasn't been fully received yet. Unlike
+/// `Request`, which buffers the whole body, `poll_next` yields it chunk by
+/// chunk as bytes arrive, so large or binary downloads don't need to fit in
+/// memory all at once.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct ResponseStream {
+    shared_state: Arc<Mutex<StreamState>>,
+    cancelled: Arc<std::sync::atomic::AtomicBool>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+struct StreamState {
+    rx: std::sync::mpsc::Receiver<Result<Vec<u8>, HttpError>>,
+    waker: Option<Waker>,
+    deadline: Option<std::time::Instant>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ResponseStream {
+    /// Yields the next chunk of the body, `None` once the body is exhausted
+    /// (or the request failed and its error has already been yielded).
+    pub fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Vec<u8>, HttpError>>> {
+        use std::sync::mpsc::TryRecvError;
+
+        let mut shared_state = self.shared_state.lock().unwrap();
+        match shared_state.rx.try_recv() {
+            Ok(chunk) => Poll::Ready(Some(chunk)),
+            Err(TryRecvError::Disconnected) => Poll::Ready(None),
+            Err(TryRecvError::Empty) => {
+                if let Some(deadline) = shared_state.deadline {
+                    if std::time::Instant::now() >= deadline {
+                        return Poll::Ready(Some(Err(HttpError::Timeout)));
+                    }
+                }
+
+                shared_state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+// Mirrors `Drop for Request` (chunk0-4): dropping a pending `ResponseStream`
+// would otherwise leave the worker thread streaming the whole body to a
+// channel nobody drains.
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for ResponseStream {
+    fn drop(&mut self) {
+        self.cancelled.store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+// There is no push-based notification from the JS side by default, so
+// pending wakers are parked here keyed by request id and resumed by
+// `quad_net_wake`, which the JS glue calls once `http_try_recv`/
+// `http_try_recv_chunk` actually have something to report. This avoids
+// spinning the single wasm thread on a synchronous self-wake every poll.
+#[cfg(target_arch = "wasm32")]
+static WAKERS: Mutex<Vec<(i32, Waker)>> = Mutex::new(Vec::new());
+
+#[cfg(target_arch = "wasm32")]
+fn park_waker(cid: i32, waker: Waker) {
+    let mut wakers = WAKERS.lock().unwrap();
+    wakers.retain(|(id, _)| *id != cid);
+    wakers.push((cid, waker));
+}
+
+/// Called from JS once `cid`'s result (or next stream chunk) is ready, so
+/// whatever `.await` is parked on it gets resumed instead of relying on a
+/// busy-polling executor.
+#[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn quad_net_wake(cid: i32) {
+    let mut wakers = WAKERS.lock().unwrap();
+    if let Some(index) = wakers.iter().position(|(id, _)| *id == cid) {
+        let (_, waker) = wakers.remove(index);
+        waker.wake();
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -88,26 +229,287 @@ pub struct Request {
 
 #[cfg(target_arch = "wasm32")]
 impl Request {
-    pub fn try_recv(&mut self) -> Option<Result<String, HttpError>> {
+    pub fn try_recv(&mut self) -> Option<Result<Response, HttpError>> {
         let js_obj = unsafe { http_try_recv(self.cid) };
 
         if js_obj.is_nil() == false {
-            let mut buf = vec![];
-            js_obj.to_byte_buffer(&mut buf);
-
-            let res = std::str::from_utf8(&buf).unwrap().to_owned();
-            return Some(Ok(res));
+            if js_obj.field_u32("timed_out") != 0 {
+                return Some(Err(HttpError::Timeout));
+            }
+            return Some(Ok(response_from_js_object(js_obj)));
         }
 
         None
     }
 }
 
+#[cfg(target_arch = "wasm32")]
+impl Future for Request {
+    type Output = Result<Response, HttpError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        match this.try_recv() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                park_waker(this.cid, cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// A handle to a response body that hasn't been fully received yet. Pulls
+/// chunks off the fetch `ReadableStream` via `http_try_recv_chunk` instead of
+/// buffering the whole body, as `Request` does.
+#[cfg(target_arch = "wasm32")]
+pub struct ResponseStream {
+    cid: i32,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl ResponseStream {
+    pub fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Vec<u8>, HttpError>>> {
+        let cid = self.cid;
+        let js_obj = unsafe { http_try_recv_chunk(cid) };
+
+        if js_obj.is_nil() {
+            park_waker(cid, cx.waker().clone());
+            return Poll::Pending;
+        }
+
+        if js_obj.field_u32("timed_out") != 0 {
+            return Poll::Ready(Some(Err(HttpError::Timeout)));
+        }
+        if js_obj.field_u32("done") != 0 {
+            return Poll::Ready(None);
+        }
+
+        let mut chunk = vec![];
+        js_obj.to_byte_buffer(&mut chunk);
+        Poll::Ready(Some(Ok(chunk)))
+    }
+}
+
+/// Unpacks the `JsObject` handed back by `http_try_recv`, which carries the
+/// status code and headers alongside the raw body bytes.
+#[cfg(target_arch = "wasm32")]
+fn response_from_js_object(js_obj: JsObject) -> Response {
+    let status_code = js_obj.field_u32("status_code") as u16;
+
+    let headers_obj = js_obj.field("headers");
+    let mut headers = vec![];
+    for i in 0..headers_obj.array_len() {
+        let header = headers_obj.array_get(i);
+        headers.push((header.field("name").to_string(), header.field("value").to_string()));
+    }
+
+    let mut body = vec![];
+    js_obj.field("body").to_byte_buffer(&mut body);
+
+    Response { status_code, headers, body }
+}
+
+/// A cookie jar that can be bound to a `RequestBuilder` so that `Set-Cookie`
+/// response headers are captured and replayed as a `Cookie` request header on
+/// later requests to the same host - the behaviour a browser gives you for
+/// free, needed here for session-based APIs and login flows from a game
+/// client.
+///
+/// Only `Max-Age` is honoured for expiry; an absolute `Expires` date is
+/// accepted but not parsed, so such cookies are kept until the jar is
+/// dropped.
+#[derive(Debug, Default)]
+pub struct CookieJar {
+    // domain -> (cookie name -> cookie)
+    cookies: std::collections::HashMap<String, std::collections::HashMap<String, StoredCookie>>,
+}
+
+#[derive(Debug, Clone)]
+struct StoredCookie {
+    value: String,
+    path: String,
+    expires: Option<std::time::SystemTime>,
+}
+
+impl CookieJar {
+    pub fn new() -> CookieJar {
+        CookieJar::default()
+    }
+
+    fn store(&mut self, default_domain: &str, set_cookie: &str) {
+        let mut parts = set_cookie.split(';').map(str::trim);
+        let name_value = match parts.next() {
+            Some(name_value) if !name_value.is_empty() => name_value,
+            _ => return,
+        };
+        let mut name_value = name_value.splitn(2, '=');
+        let name = match name_value.next() {
+            Some(name) => name.to_owned(),
+            None => return,
+        };
+        let value = name_value.next().unwrap_or("").to_owned();
+
+        let mut domain = default_domain.to_owned();
+        let mut path = "/".to_owned();
+        let mut max_age = None;
+
+        for attr in parts {
+            let mut attr = attr.splitn(2, '=');
+            let key = attr.next().unwrap_or("").to_ascii_lowercase();
+            match (key.as_str(), attr.next()) {
+                ("domain", Some(value)) => domain = value.trim_start_matches('.').to_owned(),
+                ("path", Some(value)) => path = value.to_owned(),
+                ("max-age", Some(value)) => max_age = value.parse::<u64>().ok(),
+                _ => {}
+            }
+        }
+
+        let expires =
+            max_age.map(|secs| std::time::SystemTime::now() + std::time::Duration::from_secs(secs));
+
+        self.cookies
+            .entry(domain)
+            .or_insert_with(std::collections::HashMap::new)
+            .insert(name, StoredCookie { value, path, expires });
+    }
+
+    fn header_for(&self, domain: &str, path: &str) -> Option<String> {
+        let now = std::time::SystemTime::now();
+        let pairs: Vec<String> = self
+            .cookies
+            .iter()
+            // A cookie filed under `example.com` (e.g. via `Domain=`) also
+            // applies to its subdomains, such as the host that set it.
+            .filter(|(cookie_domain, _)| {
+                domain == cookie_domain.as_str() || domain.ends_with(&format!(".{}", cookie_domain))
+            })
+            .flat_map(|(_, cookies)| cookies.iter())
+            .filter(|(_, cookie)| cookie.expires.map_or(true, |expires| expires > now))
+            .filter(|(_, cookie)| path_matches(path, &cookie.path))
+            .map(|(name, cookie)| format!("{}={}", name, cookie.value))
+            .collect();
+
+        if pairs.is_empty() {
+            None
+        } else {
+            Some(pairs.join("; "))
+        }
+    }
+}
+
+/// RFC 6265 path-match: `cookie_path` matches `request_path` if they're
+/// equal, or `request_path` has `cookie_path` as a prefix that ends right on
+/// a `/` boundary. A plain `str::starts_with` would let a cookie scoped to
+/// `/account` leak onto `/accounting`.
+fn path_matches(request_path: &str, cookie_path: &str) -> bool {
+    if request_path == cookie_path {
+        return true;
+    }
+    if !request_path.starts_with(cookie_path) {
+        return false;
+    }
+    cookie_path.ends_with('/') || request_path.as_bytes().get(cookie_path.len()) == Some(&b'/')
+}
+
+/// Splits a URL into `(host, path)`, e.g. `"http://a.com/b"` -> `("a.com", "/b")`.
+fn url_host_and_path(url: &str) -> (String, String) {
+    let without_scheme = url.splitn(2, "://").nth(1).unwrap_or(url);
+    let mut parts = without_scheme.splitn(2, '/');
+    let host = parts.next().unwrap_or("").to_owned();
+    let path = format!("/{}", parts.next().unwrap_or(""));
+    (host, path)
+}
+
+/// Builds a `Request` that is already resolved, for the mock transport below.
+#[cfg(feature = "mock")]
+fn request_from_response(response: Result<Response, HttpError>) -> Request {
+    let (tx, rx) = std::sync::mpsc::channel();
+    tx.send(response).ok();
+    Request {
+        shared_state: Arc::new(Mutex::new(SharedState { rx, waker: None, deadline: None })),
+        cancelled: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+    }
+}
+
+/// An in-memory transport for unit-testing code that issues HTTP requests,
+/// without spawning a thread or touching a real socket. Register expected
+/// calls with `mock::expect`; any `RequestBuilder::send` whose method and URL
+/// match resolves immediately from the registry instead of going over the
+/// network.
+///
+/// Gated behind the `mock` feature so downstream crates can enable it for
+/// their own tests without pulling it into release builds.
+#[cfg(feature = "mock")]
+pub mod mock {
+    use super::{Method, Response};
+    use std::sync::Mutex;
+
+    struct MockRule {
+        method: Option<Method>,
+        url_pattern: String,
+        status_code: u16,
+        body: Vec<u8>,
+    }
+
+    static MOCKS: Mutex<Vec<MockRule>> = Mutex::new(Vec::new());
+
+    /// Registers an expectation for requests to `url_pattern` (matched as a
+    /// substring of the request URL) made with `method`.
+    pub fn expect(method: Method, url_pattern: &str) -> MockExpectation {
+        MockExpectation {
+            method,
+            url_pattern: url_pattern.to_owned(),
+        }
+    }
+
+    /// Removes every registered expectation. Call this between tests so mocks
+    /// don't leak across test cases.
+    pub fn reset() {
+        MOCKS.lock().unwrap().clear();
+    }
+
+    pub struct MockExpectation {
+        method: Method,
+        url_pattern: String,
+    }
+
+    impl MockExpectation {
+        pub fn respond(self, status_code: u16, body: &str) {
+            MOCKS.lock().unwrap().push(MockRule {
+                method: Some(self.method),
+                url_pattern: self.url_pattern,
+                status_code,
+                body: body.as_bytes().to_owned(),
+            });
+        }
+    }
+
+    pub(super) fn find(method: Method, url: &str) -> Option<Response> {
+        let mocks = MOCKS.lock().unwrap();
+        mocks
+            .iter()
+            .find(|rule| rule.method.map_or(true, |m| m == method) && url.contains(&rule.url_pattern))
+            .map(|rule| Response {
+                status_code: rule.status_code,
+                headers: vec![],
+                body: rule.body.clone(),
+            })
+    }
+}
+
 pub struct RequestBuilder {
     url: String,
     method: Method,
     headers: Vec<(String, String)>,
     body: Option<String>,
+    cookie_jar: Option<Arc<Mutex<CookieJar>>>,
+    timeout: Option<std::time::Duration>,
+    #[cfg(feature = "mock")]
+    mock: Option<Response>,
 }
 
 impl RequestBuilder {
@@ -117,6 +519,52 @@ impl RequestBuilder {
             method: Method::Get,
             headers: vec![],
             body: None,
+            cookie_jar: None,
+            timeout: None,
+            #[cfg(feature = "mock")]
+            mock: None,
+        }
+    }
+
+    /// Makes this request resolve immediately to the given canned response
+    /// instead of going over the network, bypassing the global `mock`
+    /// registry. Only available with the `mock` feature enabled.
+    #[cfg(feature = "mock")]
+    pub fn with_mock(self, status_code: u16, body: &str) -> RequestBuilder {
+        RequestBuilder {
+            mock: Some(Response {
+                status_code,
+                headers: vec![],
+                body: body.as_bytes().to_owned(),
+            }),
+            ..self
+        }
+    }
+
+    /// Bounds how long the request is allowed to run. Once `timeout` elapses
+    /// the `Request` resolves to `Err(HttpError::Timeout)`.
+    ///
+    /// On native, requests that never call this still get `DEFAULT_TIMEOUT`
+    /// applied behind the scenes, so a dropped `Request` can't leave its
+    /// worker thread blocked on a hung server forever. On wasm32 a request
+    /// without a timeout has none.
+    pub fn timeout(self, timeout: std::time::Duration) -> RequestBuilder {
+        RequestBuilder {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Binds a cookie jar to this request: matching cookies already in the
+    /// jar are sent with the request, and any `Set-Cookie` headers on the
+    /// response are stored back into it.
+    ///
+    /// On wasm32 this is a no-op, since the browser already manages cookies
+    /// for same-origin requests.
+    pub fn cookie_jar(self, cookie_jar: Arc<Mutex<CookieJar>>) -> RequestBuilder {
+        RequestBuilder {
+            cookie_jar: Some(cookie_jar),
+            ..self
         }
     }
 
@@ -144,11 +592,28 @@ impl RequestBuilder {
     pub fn send(self) -> Request {
         use std::sync::mpsc::channel;
 
+        #[cfg(feature = "mock")]
+        {
+            if let Some(response) = self.mock {
+                return request_from_response(Ok(response));
+            }
+            if let Some(response) = mock::find(self.method, &self.url) {
+                return request_from_response(Ok(response));
+            }
+        }
+
         let (tx, rx) = channel();
-        let request = Request { shared_state: Arc::new(Mutex::new(SharedState { rx, waker: None })) };
+        let timeout = self.timeout.unwrap_or(DEFAULT_TIMEOUT);
+        let deadline = Some(std::time::Instant::now() + timeout);
+        let cancelled = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let request = Request {
+            shared_state: Arc::new(Mutex::new(SharedState { rx, waker: None, deadline })),
+            cancelled: cancelled.clone(),
+        };
 
         std::thread::spawn({
             let state = request.shared_state.clone();
+            let cancelled = cancelled.clone();
             move || {
                 let method = match self.method {
                     Method::Post => ureq::post,
@@ -157,19 +622,72 @@ impl RequestBuilder {
                     Method::Delete => ureq::delete,
                 };
 
+                let (host, path) = url_host_and_path(&self.url);
+
                 let mut request = method(&self.url);
-                for (header, value) in self.headers {
-                    request = request.set(&header, &value)
+                let millis = timeout.as_millis() as u64;
+                request = request.timeout_connect(millis).timeout_read(millis);
+                for (header, value) in &self.headers {
+                    request = request.set(header, value)
                 }
-                let response: Result<String, HttpError> = if let Some(body) = self.body {
-                    request.send_string(&body)
+                if let Some(cookie_jar) = &self.cookie_jar {
+                    if let Some(cookie_header) = cookie_jar.lock().unwrap().header_for(&host, &path) {
+                        request = request.set("Cookie", &cookie_header);
+                    }
+                }
+
+                let response: Result<Response, HttpError> = if let Some(body) = &self.body {
+                    request.send_string(body)
                 } else {
                     request.call()
                 }
-                    .map_err(|err| err.into())
-                    .and_then(|response| response.into_string().map_err(|err| err.into()));
+                    .map_err(|err| match deadline {
+                        Some(deadline) if std::time::Instant::now() >= deadline => HttpError::Timeout,
+                        _ => err.into(),
+                    })
+                    .and_then(|response| {
+                        let status_code = response.status();
+
+                        // `header()` only returns the first value for a repeated header
+                        // name, so a multi-cookie response would otherwise collapse to
+                        // one duplicated `Set-Cookie` entry; fetch those via `all()`
+                        // instead, same as `send_stream` does.
+                        let mut headers: Vec<(String, String)> = response
+                            .headers_names()
+                            .into_iter()
+                            .filter(|name| !name.eq_ignore_ascii_case("set-cookie"))
+                            .filter_map(|name| {
+                                response
+                                    .header(&name)
+                                    .map(|value| (name.clone(), value.to_owned()))
+                            })
+                            .collect();
+
+                        let set_cookies: Vec<String> = response
+                            .all("set-cookie")
+                            .into_iter()
+                            .map(|value| value.to_owned())
+                            .collect();
+                        headers.extend(set_cookies.iter().map(|value| ("Set-Cookie".to_owned(), value.clone())));
+
+                        if let Some(cookie_jar) = &self.cookie_jar {
+                            let mut cookie_jar = cookie_jar.lock().unwrap();
+                            for set_cookie in &set_cookies {
+                                cookie_jar.store(&host, set_cookie);
+                            }
+                        }
+
+                        let mut body = vec![];
+                        response.into_reader().read_to_end(&mut body)?;
+
+                        Ok(Response { status_code, headers, body })
+                    });
+
+                if cancelled.load(std::sync::atomic::Ordering::SeqCst) {
+                    return;
+                }
 
-                tx.send(response).unwrap();
+                tx.send(response).ok();
                 let mut shared_state = state.lock().unwrap();
                 if let Some(waker) = shared_state.waker.take() {
                     waker.wake();
@@ -180,8 +698,113 @@ impl RequestBuilder {
         request
     }
 
+    /// Like [`send`](Self::send), but yields the body chunk by chunk as it
+    /// arrives instead of buffering it all into one `Response`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn send_stream(self) -> ResponseStream {
+        use std::sync::mpsc::channel;
+
+        let (tx, rx) = channel();
+        let timeout = self.timeout.unwrap_or(DEFAULT_TIMEOUT);
+        let deadline = Some(std::time::Instant::now() + timeout);
+        let cancelled = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let stream = ResponseStream {
+            shared_state: Arc::new(Mutex::new(StreamState { rx, waker: None, deadline })),
+            cancelled: cancelled.clone(),
+        };
+
+        std::thread::spawn({
+            let state = stream.shared_state.clone();
+            let cancelled = cancelled.clone();
+            move || {
+                let wake = |state: &Arc<Mutex<StreamState>>| {
+                    if let Some(waker) = state.lock().unwrap().waker.take() {
+                        waker.wake();
+                    }
+                };
+                let as_http_error = |err: HttpError| match deadline {
+                    Some(deadline) if std::time::Instant::now() >= deadline => HttpError::Timeout,
+                    _ => err,
+                };
+
+                let method = match self.method {
+                    Method::Post => ureq::post,
+                    Method::Put => ureq::put,
+                    Method::Get => ureq::get,
+                    Method::Delete => ureq::delete,
+                };
+
+                let (host, path) = url_host_and_path(&self.url);
+
+                let mut request = method(&self.url);
+                let millis = timeout.as_millis() as u64;
+                request = request.timeout_connect(millis).timeout_read(millis);
+                for (header, value) in &self.headers {
+                    request = request.set(header, value)
+                }
+                if let Some(cookie_jar) = &self.cookie_jar {
+                    if let Some(cookie_header) = cookie_jar.lock().unwrap().header_for(&host, &path) {
+                        request = request.set("Cookie", &cookie_header);
+                    }
+                }
+
+                let result = if let Some(body) = &self.body {
+                    request.send_string(body)
+                } else {
+                    request.call()
+                };
+
+                let response = match result {
+                    Ok(response) => response,
+                    Err(err) => {
+                        tx.send(Err(as_http_error(err.into()))).ok();
+                        wake(&state);
+                        return;
+                    }
+                };
+
+                if let Some(cookie_jar) = &self.cookie_jar {
+                    let mut cookie_jar = cookie_jar.lock().unwrap();
+                    for set_cookie in response.all("set-cookie") {
+                        cookie_jar.store(&host, set_cookie);
+                    }
+                }
+
+                let mut reader = response.into_reader();
+                let mut buf = [0u8; 8192];
+                loop {
+                    if cancelled.load(std::sync::atomic::Ordering::SeqCst) {
+                        return;
+                    }
+
+                    match reader.read(&mut buf) {
+                        Ok(0) => break,
+                        Ok(n) => {
+                            if tx.send(Ok(buf[..n].to_vec())).is_err() {
+                                // Receiver (and ResponseStream) dropped; stop reading.
+                                return;
+                            }
+                            wake(&state);
+                        }
+                        Err(err) => {
+                            tx.send(Err(as_http_error(err.into()))).ok();
+                            break;
+                        }
+                    }
+                }
+                wake(&state);
+            }
+        });
+
+        stream
+    }
+
     #[cfg(target_arch = "wasm32")]
     pub fn send(&self) -> Request {
+        // No-op on wasm32: the browser already manages cookies for
+        // same-origin requests, so the jar is never consulted here.
+        let _ = &self.cookie_jar;
+
         let scheme = match self.method {
             Method::Post => 0,
             Method::Put => 1,
@@ -195,14 +818,203 @@ impl RequestBuilder {
             headers.set_field_string(&header, &value);
         }
 
+        let timeout_ms = self
+            .timeout
+            .map(|timeout| timeout.as_millis() as i32)
+            .unwrap_or(-1);
+
         let cid = unsafe {
             http_make_request(
                 scheme,
                 JsObject::string(&self.url),
                 JsObject::string(&self.body.as_ref().map(|s| s.as_str()).unwrap_or("")),
                 headers,
+                timeout_ms,
             )
         };
         Request { cid }
     }
+
+    /// Like [`send`](Self::send), but yields the body chunk by chunk, read
+    /// from the fetch `ReadableStream` as it arrives, instead of buffering
+    /// it all into one `Response`.
+    #[cfg(target_arch = "wasm32")]
+    pub fn send_stream(&self) -> ResponseStream {
+        // No-op on wasm32: the browser already manages cookies for
+        // same-origin requests, so the jar is never consulted here.
+        let _ = &self.cookie_jar;
+
+        let scheme = match self.method {
+            Method::Post => 0,
+            Method::Put => 1,
+            Method::Get => 2,
+            Method::Delete => 3,
+        };
+
+        let headers = JsObject::object();
+
+        for (header, value) in &self.headers {
+            headers.set_field_string(&header, &value);
+        }
+
+        let timeout_ms = self
+            .timeout
+            .map(|timeout| timeout.as_millis() as i32)
+            .unwrap_or(-1);
+
+        let cid = unsafe {
+            http_make_request(
+                scheme,
+                JsObject::string(&self.url),
+                JsObject::string(&self.body.as_ref().map(|s| s.as_str()).unwrap_or("")),
+                headers,
+                timeout_ms,
+            )
+        };
+        ResponseStream { cid }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn response_exposes_status_code_and_headers_alongside_the_body() {
+        let response = Response {
+            status_code: 201,
+            headers: vec![("X-Request-Id".to_owned(), "42".to_owned())],
+            body: b"created".to_vec(),
+        };
+
+        assert_eq!(response.status_code, 201);
+        assert_eq!(response.headers, vec![("X-Request-Id".to_owned(), "42".to_owned())]);
+        assert_eq!(response.into_string().unwrap(), "created");
+    }
+
+    #[test]
+    #[cfg(feature = "mock")]
+    fn mock_expect_returns_the_canned_response() {
+        mock::reset();
+        mock::expect(Method::Get, "example.com/widgets").respond(201, "created");
+
+        let response =
+            futures::executor::block_on(RequestBuilder::new("http://example.com/widgets").send())
+                .unwrap();
+
+        assert_eq!(response.status_code, 201);
+        assert_eq!(response.into_string().unwrap(), "created");
+    }
+
+    #[test]
+    #[cfg(feature = "mock")]
+    fn with_mock_bypasses_the_global_registry() {
+        mock::reset();
+        mock::expect(Method::Get, "example.com/missing").respond(200, "from registry");
+
+        let response = futures::executor::block_on(
+            RequestBuilder::new("http://example.com/missing").with_mock(404, "missing").send(),
+        )
+        .unwrap();
+
+        assert_eq!(response.status_code, 404);
+        assert_eq!(response.into_string().unwrap(), "missing");
+    }
+
+    #[test]
+    fn cookie_jar_replays_every_set_cookie_header() {
+        let mut jar = CookieJar::new();
+        jar.store("example.com", "session=abc; Path=/");
+        jar.store("example.com", "csrf=def; Path=/");
+
+        let header = jar.header_for("example.com", "/widgets").unwrap();
+        assert!(header.contains("session=abc"));
+        assert!(header.contains("csrf=def"));
+    }
+
+    #[test]
+    fn cookie_jar_drops_cookies_once_max_age_elapses() {
+        let mut jar = CookieJar::new();
+        jar.store("example.com", "session=abc; Max-Age=0");
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        assert!(jar.header_for("example.com", "/").is_none());
+    }
+
+    #[test]
+    fn cookie_jar_only_matches_requests_under_the_cookie_path() {
+        let mut jar = CookieJar::new();
+        jar.store("example.com", "session=abc; Path=/account");
+
+        assert!(jar.header_for("example.com", "/account/settings").is_some());
+        assert!(jar.header_for("example.com", "/other").is_none());
+    }
+
+    #[test]
+    fn cookie_jar_requires_a_path_segment_boundary() {
+        let mut jar = CookieJar::new();
+        jar.store("example.com", "session=abc; Path=/account");
+
+        // "/accounting" shares the "/account" prefix but isn't under it.
+        assert!(jar.header_for("example.com", "/accounting").is_none());
+    }
+
+    #[test]
+    fn request_builder_stores_the_configured_timeout() {
+        let builder = RequestBuilder::new("http://example.com/").timeout(std::time::Duration::from_secs(5));
+        assert_eq!(builder.timeout, Some(std::time::Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn dropping_a_pending_request_sets_the_cancelled_flag() {
+        let (_tx, rx) = std::sync::mpsc::channel();
+        let cancelled = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let request = Request {
+            shared_state: Arc::new(Mutex::new(SharedState { rx, waker: None, deadline: None })),
+            cancelled: cancelled.clone(),
+        };
+
+        drop(request);
+
+        assert!(cancelled.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[test]
+    fn dropping_a_pending_response_stream_sets_the_cancelled_flag() {
+        let (_tx, rx) = std::sync::mpsc::channel();
+        let cancelled = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let stream = ResponseStream {
+            shared_state: Arc::new(Mutex::new(StreamState { rx, waker: None, deadline: None })),
+            cancelled: cancelled.clone(),
+        };
+
+        drop(stream);
+
+        assert!(cancelled.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[test]
+    fn response_stream_yields_chunks_then_ends_on_disconnect() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut stream = ResponseStream {
+            shared_state: Arc::new(Mutex::new(StreamState { rx, waker: None, deadline: None })),
+            cancelled: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+        };
+
+        tx.send(Ok(vec![1, 2, 3])).unwrap();
+        let waker = futures::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        match Pin::new(&mut stream).poll_next(&mut cx) {
+            Poll::Ready(Some(Ok(chunk))) => assert_eq!(chunk, vec![1, 2, 3]),
+            other => panic!("expected a chunk, got {:?}", other),
+        }
+
+        drop(tx);
+
+        match Pin::new(&mut stream).poll_next(&mut cx) {
+            Poll::Ready(None) => {}
+            other => panic!("expected end of stream, got {:?}", other),
+        }
+    }
 }